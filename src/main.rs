@@ -1,10 +1,14 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
 use glib::{clone, Continue, MainContext, PRIORITY_DEFAULT};
 use gtk::glib::timeout_future_seconds;
 use gtk::prelude::*;
-use gtk::{glib, Application, ApplicationWindow, Button};
+use gtk::{glib, Application, ApplicationWindow, Box, Button, Orientation, ProgressBar};
 
 const APP_ID: &str = "event_loop_test";
 
@@ -15,6 +19,11 @@ fn main() -> glib::ExitCode {
     func_vec.push(&build_app_with_stuck_behavior);
     func_vec.push(&build_app_with_new_thread);
     func_vec.push(&build_app_with_new_thead_and_button_disable);
+    func_vec.push(&build_app_with_async_channel_and_button_disable);
+    func_vec.push(&build_app_with_generation_guard);
+    func_vec.push(&build_app_with_cancellable_task);
+    func_vec.push(&build_app_with_progress_bar);
+    func_vec.push(&build_app_with_reusable_worker);
     func_vec.push(&build_app_with_async_button);
 
     for func in func_vec{
@@ -115,6 +124,255 @@ fn build_app_with_new_thead_and_button_disable( app: &Application ){
 
 }
 
+/// `MainContext::channel` and `receiver.attach` above are the classic gtk-rs idiom,
+/// but they have since been deprecated (and eventually removed) in favour of the
+/// `async-channel` crate, which plays nicer with the `async`/`.await` flow the rest
+/// of this tutorial is moving towards.
+///
+/// The sending side stays on the worker thread and uses `send_blocking`, since that
+/// thread has no executor of its own. The receiving side is consumed inside a future
+/// spawned onto the main context with `spawn_future_local`, which is the modern
+/// replacement for `receiver.attach`.
+///
+fn build_app_with_async_channel_and_button_disable( app: &Application ){
+
+    let (sender, receiver) = async_channel::unbounded();
+    let button = build_button();
+
+    button.connect_clicked(move |_|{
+            let sender = sender.clone();
+            // here is where the thread spawned
+            thread::spawn(move | |{
+                // deactivate the button (similar to disable in html + js) until the wait has ended
+                sender.send_blocking(false).expect("Error during channel send.");
+                let a_few_moments_later = Duration::from_secs(10);
+                std::thread::sleep(a_few_moments_later);
+
+                // enable the button again
+                sender.send_blocking(true).expect("Error during channel send.");
+            });
+        }
+    );
+
+    // The main loop awaits each message as soon as it receives the message
+    MainContext::default().spawn_future_local(clone!(
+        @weak button => async move {
+            while let Ok(enable) = receiver.recv().await {
+                button.set_sensitive(enable);
+            }
+        }
+    ));
+
+    present_button_interface(&app, &button, "Button with async-channel and Disable (sensitive) behavior");
+
+}
+
+/// Disabling the button only prevents *new* clicks from queuing work; it does nothing
+/// about a result that arrives after the state it was computed for has already moved on.
+/// If the user could somehow trigger the task again before the first one reports back
+/// (e.g. through another code path), the older result would land last and clobber the
+/// newer one.
+///
+/// The fix is a generation token: every click captures the current generation before
+/// spawning, the worker hands that same generation back over the channel, and the
+/// receiver only applies a result if its generation still matches the current one.
+/// Anything older is stale and gets dropped. This is the same guard the Game-of-Life
+/// runner uses to ignore ticks from a thread that is no longer the active one.
+///
+fn build_app_with_generation_guard( app: &Application ){
+
+    let (sender, receiver) = async_channel::unbounded();
+    let button = build_button();
+    let generation = Rc::new(Cell::new(0u64));
+
+    button.connect_clicked(clone!(
+        @weak button, @strong generation => move |_| {
+            // bump the generation and capture it before handing work to the thread
+            generation.set(generation.get() + 1);
+            let this_generation = generation.get();
+
+            button.set_sensitive(false);
+
+            let sender = sender.clone();
+            thread::spawn(move | |{
+                let a_few_moments_later = Duration::from_secs(10);
+                std::thread::sleep(a_few_moments_later);
+
+                // hand the captured generation back alongside the result so the
+                // receiver can tell whether this task is still the current one
+                sender.send_blocking(this_generation).expect("Error during channel send.");
+            });
+        }
+    ));
+
+    MainContext::default().spawn_future_local(clone!(
+        @weak button, @strong generation => async move {
+            while let Ok(completed_generation) = receiver.recv().await {
+                // a later click would have bumped `generation` past this value;
+                // only re-enable the button if no newer click has superseded it
+                if completed_generation == generation.get() {
+                    button.set_sensitive(true);
+                }
+            }
+        }
+    ));
+
+    present_button_interface(&app, &button, "Button with Generation Guard");
+
+}
+
+/// None of the demos above let the user back out of the 10 second task once it has
+/// started. Here the button doubles as a Start/Cancel toggle: the first click spawns
+/// the worker, the second click just flips a shared `Arc<AtomicBool>` that the worker
+/// polls between short sleeps instead of one long `thread::sleep`. This is cooperative
+/// cancellation - the worker still has to check in regularly, but it no longer has to
+/// run to completion regardless of what the user wants.
+///
+fn build_app_with_cancellable_task( app: &Application ){
+
+    let (sender, receiver) = async_channel::unbounded();
+    let button = build_button();
+    button.set_label("Start");
+    let running = Rc::new(Cell::new(false));
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+
+    button.connect_clicked(clone!(
+        @weak button, @strong running, @strong cancel_flag => move |_| {
+            if running.get() {
+                // second click: ask the worker to stop at its next check-in
+                cancel_flag.store(true, Ordering::SeqCst);
+                button.set_sensitive(false);
+                return;
+            }
+
+            running.set(true);
+            cancel_flag.store(false, Ordering::SeqCst);
+            button.set_label("Cancel");
+
+            let sender = sender.clone();
+            let cancel_flag = Arc::clone(&cancel_flag);
+            thread::spawn(move | |{
+                let step = Duration::from_millis(100);
+                for _ in 0..100 {
+                    if cancel_flag.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    std::thread::sleep(step);
+                }
+                // whether it ran to completion or was cancelled, report back so the
+                // UI can reset itself
+                sender.send_blocking(()).expect("Error during channel send.");
+            });
+        }
+    ));
+
+    MainContext::default().spawn_future_local(clone!(
+        @weak button, @strong running => async move {
+            while receiver.recv().await.is_ok() {
+                running.set(false);
+                button.set_label("Start");
+                button.set_sensitive(true);
+            }
+        }
+    ));
+
+    present_button_interface(&app, &button, "Cancellable Task");
+
+}
+
+/// All the demos above only ever show the button itself going from enabled to
+/// disabled, which proves the window doesn't freeze but doesn't really show the main
+/// loop *doing* anything while the worker runs. Here the worker reports its fractional
+/// progress every ~100ms over an `async-channel`, and a task spawned on the main
+/// context applies each update to a `ProgressBar` as it arrives - the bar filling in
+/// while the rest of the UI stays responsive is the "rendering continues off-thread"
+/// point made concrete.
+///
+fn build_app_with_progress_bar( app: &Application ){
+
+    let (sender, receiver) = async_channel::unbounded();
+    let button = build_button();
+    let progress_bar = ProgressBar::builder()
+        .margin_start(12)
+        .margin_end(12)
+        .margin_bottom(12)
+        .build();
+
+    button.connect_clicked(clone!(
+        @weak button => move |_| {
+            button.set_sensitive(false);
+
+            let sender = sender.clone();
+            thread::spawn(move | |{
+                let step = Duration::from_millis(100);
+                for i in 1..=100 {
+                    std::thread::sleep(step);
+                    sender.send_blocking(i as f64 / 100.0).expect("Error during channel send.");
+                }
+            });
+        }
+    ));
+
+    MainContext::default().spawn_future_local(clone!(
+        @weak button, @weak progress_bar => async move {
+            while let Ok(fraction) = receiver.recv().await {
+                progress_bar.set_fraction(fraction);
+                if fraction >= 1.0 {
+                    button.set_sensitive(true);
+                }
+            }
+        }
+    ));
+
+    let container = Box::builder()
+        .orientation(Orientation::Vertical)
+        .build();
+    container.append(&button);
+    container.append(&progress_bar);
+
+    present_button_interface(app, &container, "Button with Progress Bar");
+
+}
+
+/// `build_app_with_new_thread` (and every demo between it and this one) spawn a brand
+/// new OS thread on every click, and the doc comment on that function already points
+/// out that users can keep pressing and submitting more threads. Here a single worker thread is spawned once, when the
+/// window is built, and clicks just push a job onto an `async-channel` work queue the
+/// worker reads from in a loop. Results flow back over a second channel consumed by
+/// `spawn_future_local`. Because there is only ever one worker thread, concurrency is
+/// naturally capped at a single in-flight job - no `set_sensitive` bookkeeping needed.
+///
+fn build_app_with_reusable_worker( app: &Application ){
+
+    let (job_sender, job_receiver) = async_channel::unbounded::<()>();
+    let (result_sender, result_receiver) = async_channel::unbounded::<()>();
+    let button = build_button();
+
+    // the one long-lived worker thread: it just blocks waiting for the next job
+    thread::spawn(move | |{
+        while job_receiver.recv_blocking().is_ok() {
+            let a_few_moments_later = Duration::from_secs(10);
+            std::thread::sleep(a_few_moments_later);
+            result_sender.send_blocking(()).expect("Error during channel send.");
+        }
+    });
+
+    button.connect_clicked(move |_|{
+        // hand off a job instead of spawning a thread; if the worker is still busy with
+        // a previous click, the job waits in the channel until the worker takes it
+        job_sender.send_blocking(()).expect("Error during channel send.");
+    });
+
+    MainContext::default().spawn_future_local(async move {
+        // draining the result queue here is what proves jobs are coming back to the
+        // main loop rather than just running and being forgotten on the worker thread
+        while result_receiver.recv().await.is_ok() {}
+    });
+
+    present_button_interface(&app, &button, "Button with Reusable Worker Thread");
+
+}
+
 /// It is possible use MainContext in an async fashion by using spawn_local function,
 /// to prevent the process from freezing by a button, without the additional thread.
 /// 
@@ -154,12 +412,12 @@ fn build_button() -> Button{
     button
 }
 
-fn present_button_interface(app: &Application, button: &Button, title: &str){
+fn present_button_interface(app: &Application, child: &impl IsA<gtk::Widget>, title: &str){
     // craete all the remaining structure of the app
     let window = ApplicationWindow::builder()
         .application(app)
         .title("GTK4 Event Tutorial - ".to_owned() + title)
-        .child(button)
+        .child(child)
         .build();
 
     window.present();